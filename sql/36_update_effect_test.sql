@@ -0,0 +1,34 @@
+-- NONE: the period is unchanged
+SELECT sql_saga.temporal_update_effect(daterange('2017-11-22', '2017-11-28'), daterange('2017-11-22', '2017-11-28'));
+
+-- SHRINK: the new period lies within the old one
+SELECT sql_saga.temporal_update_effect(daterange('2017-11-22', '2017-11-28'), daterange('2017-11-22', '2017-11-24'));
+SELECT sql_saga.temporal_update_effect(daterange('2017-11-22', '2017-11-28'), daterange('2017-11-24', '2017-11-26'));
+
+-- GROW: the new period covers the old one
+SELECT sql_saga.temporal_update_effect(daterange('2017-11-22', '2017-11-28'), daterange('2017-11-20', '2017-11-28'));
+SELECT sql_saga.temporal_update_effect(daterange('2017-11-22', '2017-11-28'), daterange('2017-11-22', 'infinity'));
+
+-- MOVE: the new period is neither within nor covering the old one
+SELECT sql_saga.temporal_update_effect(daterange('2017-11-22', '2017-11-28'), daterange('2017-11-24', '2017-11-30'));
+SELECT sql_saga.temporal_update_effect(daterange('2017-11-22', '2017-11-28'), daterange('2017-12-01', '2017-12-05'));
+
+-- An empty period covers no time, so changing from or to one is a MOVE
+SELECT sql_saga.temporal_update_effect('empty'::daterange, daterange('2017-11-22', '2017-11-28'));
+SELECT sql_saga.temporal_update_effect(daterange('2017-11-22', '2017-11-28'), 'empty'::daterange);
+SELECT sql_saga.temporal_update_effect('empty'::daterange, 'empty'::daterange);
+
+-- It works for any range type
+SELECT sql_saga.temporal_update_effect(int4range(1, 10), int4range(1, 5));
+SELECT sql_saga.temporal_update_effect(tstzrange('2017-11-27 06:00:00', '2017-11-27 12:00:00'), tstzrange('2017-11-27 06:00:00', '2017-11-27 17:00:00'));
+
+-- NULL:
+
+-- it is unknown when either period is null
+SELECT sql_saga.temporal_update_effect(NULL, daterange('2017-11-22', '2017-11-28'));
+SELECT sql_saga.temporal_update_effect(daterange('2017-11-22', '2017-11-28'), NULL);
+
+-- Errors:
+
+-- it fails when the periods are of different types
+SELECT sql_saga.temporal_update_effect(int4range(1, 10), daterange('2017-11-22', '2017-11-28'));
\ No newline at end of file
@@ -0,0 +1,65 @@
+CREATE TABLE rates (
+  id INTEGER,
+  rate INTEGER,
+  edited_by TEXT,
+  valid_from DATE,
+  valid_to DATE
+);
+SELECT sql_saga.add_era('rates', 'valid_from', 'valid_to');
+
+INSERT INTO rates(id, rate, edited_by, valid_from, valid_to) VALUES
+  (1, 10, 'alice', '2017-01-01', '2017-02-01'),
+  (1, 10, 'bob',   '2017-02-01', '2017-03-01'),
+  (1, 20, 'bob',   '2017-03-01', '2017-04-01'),
+  (1, 10, 'alice', '2017-04-01', '2017-05-01'),
+  (2, 10, 'alice', '2017-01-01', '2017-02-01'),
+  (2, 10, 'alice', '2017-02-01', '2017-03-01'),
+  (2, 10, 'alice', '2017-03-01', 'infinity'),
+  (3, 30, 'carol', '2017-01-01', '2017-02-01'),
+  (3, 30, 'carol', '2017-02-15', '2017-03-01')
+;
+
+-- only rows with identical values are merged
+SELECT * FROM sql_saga.coalesce_timeline('rates');
+
+-- ephemeral columns do not prevent a merge, and the latest value is kept
+SELECT * FROM sql_saga.coalesce_timeline('rates', 'valid', ARRAY['edited_by']);
+
+-- the data itself is not modified
+SELECT count(*) FROM rates;
+
+-- a column named like the internal table alias is not mistaken for the row
+CREATE TABLE tagged (
+  id INTEGER,
+  t JSONB,
+  valid_from DATE,
+  valid_to DATE
+);
+SELECT sql_saga.add_era('tagged', 'valid_from', 'valid_to');
+
+INSERT INTO tagged(id, t, valid_from, valid_to) VALUES
+  (1, '{"a": 1}', '2017-01-01', '2017-02-01'),
+  (2, '{"a": 1}', '2017-02-01', '2017-03-01')
+;
+
+SELECT * FROM sql_saga.coalesce_timeline('tagged');
+
+SELECT sql_saga.drop_era('tagged');
+DROP TABLE tagged;
+
+-- Errors:
+
+-- it fails for an unknown era
+SELECT * FROM sql_saga.coalesce_timeline('rates', 'invalid');
+
+-- it fails for an unknown ephemeral column
+SELECT * FROM sql_saga.coalesce_timeline('rates', 'valid', ARRAY['edited_at']);
+
+-- it fails when no ephemeral columns list is given
+SELECT * FROM sql_saga.coalesce_timeline('rates', 'valid', NULL);
+
+-- it fails when an era column is ephemeral
+SELECT * FROM sql_saga.coalesce_timeline('rates', 'valid', ARRAY['valid_to']);
+
+SELECT sql_saga.drop_era('rates');
+DROP TABLE rates;
\ No newline at end of file
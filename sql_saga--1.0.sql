@@ -8,6 +8,7 @@ GRANT USAGE ON SCHEMA sql_saga TO PUBLIC;
 CREATE TYPE sql_saga.drop_behavior AS ENUM ('CASCADE', 'RESTRICT');
 CREATE TYPE sql_saga.fk_actions AS ENUM ('CASCADE', 'SET NULL', 'SET DEFAULT', 'RESTRICT', 'NO ACTION');
 CREATE TYPE sql_saga.fk_match_types AS ENUM ('FULL', 'PARTIAL', 'SIMPLE');
+CREATE TYPE sql_saga.update_effect AS ENUM ('NONE', 'SHRINK', 'MOVE', 'GROW');
 
 /*
  * All referencing columns must be either name or regsomething in order for
@@ -3444,3 +3445,134 @@ AS
 $function$
     SELECT sv1 = ev2;
 $function$;
+
+/* Update effects */
+
+/*
+ * temporal_update_effect(old_range anyrange, new_range anyrange) -
+ * Classifies how a period changes when a row is updated:
+ * NONE if it is unchanged, SHRINK if the new period lies within the old one,
+ * GROW if the new period covers the old one, and MOVE otherwise.  An empty
+ * range covers no time at all, so a change from or to an empty period is a
+ * MOVE rather than a GROW or SHRINK.
+ */
+CREATE FUNCTION sql_saga.temporal_update_effect(old_range anyrange, new_range anyrange)
+ RETURNS sql_saga.update_effect
+ LANGUAGE sql
+ IMMUTABLE
+ STRICT
+AS
+$function$
+    SELECT CASE
+        WHEN new_range = old_range THEN 'NONE'
+        WHEN isempty(old_range) OR isempty(new_range) THEN 'MOVE'
+        WHEN old_range @> new_range THEN 'SHRINK'
+        WHEN new_range @> old_range THEN 'GROW'
+        ELSE 'MOVE'
+    END::sql_saga.update_effect;
+$function$;
+
+/* Timeline diagnostics */
+
+/*
+ * coalesce_timeline(table_name regclass, era_name name, ephemeral_column_names name[]) -
+ * Returns the timeline of the table as it would look if adjacent rows with
+ * identical values were merged, without modifying any data.  Every column
+ * outside the era and the ephemeral columns counts as identity, so rows only
+ * merge when they agree on all of them, key columns included.  Differences in
+ * the ephemeral columns (such as edit timestamps) do not prevent a merge; the
+ * merged row carries the values of its latest part.  merged_row_count is the
+ * number of stored rows each resulting row replaces.
+ */
+CREATE FUNCTION sql_saga.coalesce_timeline(
+        table_name regclass,
+        era_name name DEFAULT 'valid',
+        ephemeral_column_names name[] DEFAULT '{}')
+ RETURNS TABLE (row_data jsonb, merged_row_count bigint)
+ LANGUAGE plpgsql
+ STABLE
+AS
+$function$
+#variable_conflict use_variable
+DECLARE
+    era_row sql_saga.era;
+    idx integer;
+BEGIN
+    IF table_name IS NULL THEN
+        RAISE EXCEPTION 'no table name specified';
+    END IF;
+
+    IF era_name IS NULL THEN
+        RAISE EXCEPTION 'no era name specified';
+    END IF;
+
+    /* A NULL list would make every row compare equal, so refuse it */
+    IF ephemeral_column_names IS NULL THEN
+        RAISE EXCEPTION 'no ephemeral column names specified';
+    END IF;
+
+    SELECT p.*
+    INTO era_row
+    FROM sql_saga.era AS p
+    WHERE (p.table_name, p.era_name) = (table_name, era_name);
+
+    IF NOT FOUND THEN
+        RAISE EXCEPTION 'era "%" does not exist', era_name;
+    END IF;
+
+    /* Report if any ephemeral columns weren't found */
+    SELECT n.ordinality
+    INTO idx
+    FROM unnest(ephemeral_column_names) WITH ORDINALITY AS n (name, ordinality)
+    WHERE NOT EXISTS (
+        SELECT FROM pg_catalog.pg_attribute AS a
+        WHERE (a.attrelid, a.attname) = (table_name, n.name)
+          AND a.attnum > 0
+          AND NOT a.attisdropped)
+    ORDER BY n.ordinality
+    LIMIT 1;
+
+    IF idx IS NOT NULL THEN
+        RAISE EXCEPTION 'column "%" does not exist', ephemeral_column_names[idx];
+    END IF;
+
+    /* The era columns define the timeline, so they cannot be ephemeral */
+    IF era_row.start_column_name = ANY (ephemeral_column_names) THEN
+        RAISE EXCEPTION 'column "%" is part of era "%"', era_row.start_column_name, era_name;
+    END IF;
+    IF era_row.end_column_name = ANY (ephemeral_column_names) THEN
+        RAISE EXCEPTION 'column "%" is part of era "%"', era_row.end_column_name, era_name;
+    END IF;
+
+    /*
+     * Rows with the same values outside the era and the ephemeral columns
+     * form an island as long as each one starts where the previous one ended.
+     */
+    RETURN QUERY EXECUTE format($$
+        WITH timeline AS (
+            SELECT to_jsonb(t.*) - $1::text[] - %2$L - %3$L AS data,
+                   to_jsonb(t.*) AS full_data,
+                   t.%2$I AS start_value,
+                   t.%3$I AS end_value
+            FROM %1$s AS t
+        ), flagged AS (
+            SELECT tl.*,
+                   CASE WHEN lag(tl.end_value) OVER w = tl.start_value THEN 0 ELSE 1 END AS island_start
+            FROM timeline AS tl
+            WINDOW w AS (PARTITION BY tl.data ORDER BY tl.start_value)
+        ), islands AS (
+            SELECT f.*,
+                   sum(f.island_start) OVER (PARTITION BY f.data ORDER BY f.start_value) AS island
+            FROM flagged AS f
+        )
+        SELECT (array_agg(i.full_data ORDER BY i.start_value DESC))[1]
+                   || jsonb_build_object(%2$L, min(i.start_value), %3$L, max(i.end_value)),
+               count(*)
+        FROM islands AS i
+        GROUP BY i.data, i.island
+        ORDER BY i.data, min(i.start_value)
+        $$,
+        table_name, era_row.start_column_name, era_row.end_column_name)
+    USING ephemeral_column_names;
+END;
+$function$;